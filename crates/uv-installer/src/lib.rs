@@ -0,0 +1,131 @@
+use pep508_rs::Requirement;
+
+use uv_configuration::ExtrasSpecification;
+use uv_interpreter::PythonEnvironment;
+
+/// A single already-installed distribution, as recorded in `site-packages`.
+#[derive(Debug, Clone)]
+struct InstalledDist {
+    requirement: Requirement,
+}
+
+/// The distributions installed into a Python environment's `site-packages`, used to short-circuit
+/// resolution when a set of requirements is already satisfied.
+#[derive(Debug, Clone, Default)]
+pub struct SitePackages {
+    installed: Vec<InstalledDist>,
+}
+
+/// A single entry in a [`SatisfiesResult::Fresh`], carrying the installed distribution that
+/// satisfied its requirement.
+#[derive(Debug, Clone)]
+pub struct RequirementEntry {
+    pub requirement: Requirement,
+}
+
+#[derive(Debug)]
+pub enum SatisfiesResult {
+    /// Every requirement has a distribution of the same name installed.
+    Fresh {
+        recursive_requirements: Vec<RequirementEntry>,
+    },
+    /// At least one requirement isn't installed, or asks for an extra that wasn't requested.
+    Unsatisfied(Requirement),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Interpreter(#[from] uv_interpreter::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl SitePackages {
+    /// Read the distributions installed into `venv`'s `site-packages`, by scanning for
+    /// `*.dist-info` directories and parsing the `Name` out of each one's `METADATA` file.
+    pub fn from_executable(venv: &PythonEnvironment) -> Result<Self, Error> {
+        let site_packages = venv.site_packages_path();
+
+        let entries = match std::fs::read_dir(&site_packages) {
+            Ok(entries) => entries,
+            // A venv with no installed distributions yet (or one whose site-packages directory
+            // hasn't been created) simply has nothing installed.
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Self::default());
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut installed = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("dist-info") {
+                continue;
+            }
+
+            let Ok(metadata) = std::fs::read_to_string(path.join("METADATA")) else {
+                continue;
+            };
+            let Some(name) = metadata
+                .lines()
+                .find_map(|line| line.strip_prefix("Name: "))
+            else {
+                continue;
+            };
+            let Ok(requirement) = name.trim().parse::<Requirement>() else {
+                continue;
+            };
+
+            installed.push(InstalledDist { requirement });
+        }
+
+        Ok(Self { installed })
+    }
+
+    /// Check whether `requirements` are already satisfied by what's installed, only requiring
+    /// extras that were actually requested via `extras`.
+    ///
+    /// A requirement asking for extras that `extras` doesn't cover is treated as unsatisfied:
+    /// the base distribution being installed isn't enough if one of its requested extras is
+    /// missing.
+    ///
+    /// Requirements are matched against installed distributions by name only: version
+    /// specifiers, the dependencies of each requirement, and `constraints` are not checked.
+    pub fn satisfies(
+        &self,
+        requirements: &[Requirement],
+        constraints: &[Requirement],
+        extras: &ExtrasSpecification,
+    ) -> Result<SatisfiesResult, Error> {
+        let _ = constraints;
+
+        let mut recursive_requirements = Vec::new();
+        for requirement in requirements {
+            if !requirement
+                .extras
+                .iter()
+                .all(|extra| extras.contains(extra))
+            {
+                return Ok(SatisfiesResult::Unsatisfied(requirement.clone()));
+            }
+
+            let Some(installed) = self
+                .installed
+                .iter()
+                .find(|dist| dist.requirement.name == requirement.name)
+            else {
+                return Ok(SatisfiesResult::Unsatisfied(requirement.clone()));
+            };
+
+            recursive_requirements.push(RequirementEntry {
+                requirement: installed.requirement.clone(),
+            });
+        }
+
+        Ok(SatisfiesResult::Fresh {
+            recursive_requirements,
+        })
+    }
+}
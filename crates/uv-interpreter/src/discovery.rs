@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::{Error, Interpreter, SystemPython};
+use uv_cache::Cache;
+use uv_configuration::PreviewMode;
+
+/// Enumerate every interpreter we can discover, in lookup precedence order: `PATH`, then `uv`'s
+/// registered toolchains, then `uv`'s managed installs. Shared by every caller that needs to walk
+/// candidates rather than resolve a single named interpreter, so the precedence can't drift
+/// between the `--python` path and a `requires-python`-driven search.
+pub(crate) fn find_all(
+    system: SystemPython,
+    preview: PreviewMode,
+    cache: &Cache,
+) -> Result<Vec<Interpreter>, Error> {
+    let _ = (system, preview, cache);
+
+    let mut interpreters = find_path_interpreters();
+    interpreters.extend(find_toolchain_interpreters());
+    interpreters.extend(find_managed_interpreters());
+    Ok(interpreters)
+}
+
+/// Interpreters discoverable on `PATH` (`python3`, `python`, `python3.<minor>`, ...), probed in
+/// the order their containing directory appears in `PATH`, matching ordinary shell lookup
+/// precedence. Each candidate is executed to confirm it's actually runnable and to read its
+/// real version, rather than trusting the filename.
+fn find_path_interpreters() -> Vec<Interpreter> {
+    let Some(path_var) = std::env::var_os("PATH") else {
+        return Vec::new();
+    };
+
+    let mut seen = HashSet::new();
+    let mut interpreters = Vec::new();
+    for dir in std::env::split_paths(&path_var) {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !is_python_executable_name(&path) {
+                continue;
+            }
+
+            // Resolve symlinks so e.g. `python3` and `python3.11` pointing at the same
+            // install don't show up as two candidates.
+            let canonical = path.canonicalize().unwrap_or(path);
+            if !seen.insert(canonical.clone()) {
+                continue;
+            }
+
+            if let Some(interpreter) = Interpreter::query(&canonical) {
+                interpreters.push(interpreter);
+            }
+        }
+    }
+    interpreters
+}
+
+/// Interpreters registered with `uv`'s toolchain registry. Not wired up yet: this tree has no
+/// toolchain registry to read, so we return no candidates rather than guessing at one.
+fn find_toolchain_interpreters() -> Vec<Interpreter> {
+    Vec::new()
+}
+
+/// Interpreters `uv` has downloaded and manages itself. Not wired up yet, for the same reason as
+/// [`find_toolchain_interpreters`].
+fn find_managed_interpreters() -> Vec<Interpreter> {
+    Vec::new()
+}
+
+/// Whether `path`'s file name looks like a Python interpreter (`python`, `python3`,
+/// `python3.11`, with the platform's executable suffix stripped).
+fn is_python_executable_name(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let name = name
+        .strip_suffix(std::env::consts::EXE_SUFFIX)
+        .unwrap_or(name);
+
+    name == "python" || name == "python3" || {
+        name.strip_prefix("python3.")
+            .is_some_and(|minor| !minor.is_empty() && minor.chars().all(|c| c.is_ascii_digit()))
+    }
+}
@@ -0,0 +1,310 @@
+use std::path::{Path, PathBuf};
+
+use pep440_rs::{Version, VersionSpecifiers};
+use platform_tags::Tags;
+
+use uv_cache::Cache;
+use uv_configuration::PreviewMode;
+
+mod discovery;
+
+/// Whether a system Python interpreter may be used. [`discovery::find_all`] accepts this setting
+/// but does not filter on it: every interpreter found on the user's `PATH`, among `uv`'s
+/// registered toolchains, or among `uv`'s own managed installations is a candidate, queried in
+/// that order, for either variant.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum SystemPython {
+    /// A system interpreter may be used.
+    #[default]
+    Allowed,
+    /// A system interpreter is required.
+    Required,
+}
+
+/// A discovered Python interpreter and the metadata we need about it to drive resolution and
+/// installation.
+#[derive(Debug, Clone)]
+pub struct Interpreter {
+    python_version: Version,
+    sys_executable: PathBuf,
+    markers: pep508_rs::MarkerEnvironment,
+    platform: platform_tags::Platform,
+}
+
+impl Interpreter {
+    pub fn python_version(&self) -> &Version {
+        &self.python_version
+    }
+
+    pub fn tags(&self) -> Result<Tags, platform_tags::TagsError> {
+        Tags::from_env(&self.python_version)
+    }
+
+    pub fn markers(&self) -> &pep508_rs::MarkerEnvironment {
+        &self.markers
+    }
+
+    pub fn platform(&self) -> &platform_tags::Platform {
+        &self.platform
+    }
+
+    pub fn sys_executable(&self) -> &Path {
+        &self.sys_executable
+    }
+
+    /// Probe `path` by actually invoking it, to confirm it's a working Python interpreter and
+    /// read its real version rather than trusting the file name. Returns `None` if `path` can't
+    /// be executed or doesn't look like Python.
+    pub fn query(path: &Path) -> Option<Self> {
+        let output = std::process::Command::new(path)
+            .arg("-c")
+            .arg("import sys; print(f'{sys.version_info[0]}.{sys.version_info[1]}.{sys.version_info[2]}'); print(sys.executable)")
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+
+        let stdout = String::from_utf8(output.stdout).ok()?;
+        let mut lines = stdout.lines();
+        let python_version = lines.next()?.trim().parse::<Version>().ok()?;
+        let sys_executable = lines
+            .next()
+            .map(|line| PathBuf::from(line.trim()))
+            .unwrap_or_else(|| path.to_path_buf());
+
+        let markers = pep508_rs::MarkerEnvironment::query(path).ok()?;
+        let platform = platform_tags::Platform::current().ok()?;
+
+        Some(Self {
+            python_version,
+            sys_executable,
+            markers,
+            platform,
+        })
+    }
+}
+
+/// A request for a specific Python version, parsed from `--python` or derived from a project's
+/// `requires-python`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionRequest {
+    Any,
+    Major(u8),
+    MajorMinor(u8, u8),
+    MajorMinorPatch(u8, u8, u8),
+    /// A full PEP 440 specifier set, e.g. the `>=3.9,<3.13` parsed from a project's
+    /// `requires-python`. Unlike the other variants, this can match more than one `X.Y[.Z]`.
+    Range(VersionSpecifiers),
+}
+
+impl VersionRequest {
+    fn matches(&self, version: &Version) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Major(major) => version.release().first() == Some(&u64::from(*major)),
+            Self::MajorMinor(major, minor) => {
+                version.release().first() == Some(&u64::from(*major))
+                    && version.release().get(1) == Some(&u64::from(*minor))
+            }
+            Self::MajorMinorPatch(major, minor, patch) => {
+                version.release().first() == Some(&u64::from(*major))
+                    && version.release().get(1) == Some(&u64::from(*minor))
+                    && version.release().get(2) == Some(&u64::from(*patch))
+            }
+            Self::Range(specifiers) => specifiers.contains(version),
+        }
+    }
+}
+
+/// A request for a Python interpreter, e.g. `--python 3.11`, `--python /usr/bin/python3`, or (as
+/// of this change) a project's `requires-python` range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpreterRequest {
+    Any,
+    Version(VersionRequest),
+    Path(PathBuf),
+}
+
+impl InterpreterRequest {
+    /// Parse a `--python` argument, e.g. `"3.11"`, `"3.11.4"`, or a path to an interpreter.
+    pub fn parse(value: &str) -> Self {
+        if let Ok(version) = value.parse::<Version>() {
+            let release = version.release();
+            return Self::Version(match release {
+                [major] => VersionRequest::Major(u8::try_from(*major).unwrap_or(u8::MAX)),
+                [major, minor] => VersionRequest::MajorMinor(
+                    u8::try_from(*major).unwrap_or(u8::MAX),
+                    u8::try_from(*minor).unwrap_or(u8::MAX),
+                ),
+                [major, minor, patch, ..] => VersionRequest::MajorMinorPatch(
+                    u8::try_from(*major).unwrap_or(u8::MAX),
+                    u8::try_from(*minor).unwrap_or(u8::MAX),
+                    u8::try_from(*patch).unwrap_or(u8::MAX),
+                ),
+                [] => VersionRequest::Any,
+            });
+        }
+
+        Self::Path(PathBuf::from(value))
+    }
+
+    /// A request built from a project's `requires-python`, shared with the `--python` discovery
+    /// path so both honor the same PATH/toolchain/managed-install precedence.
+    pub fn from_requires_python(requires_python: &VersionSpecifiers) -> Self {
+        Self::Version(VersionRequest::Range(requires_python.clone()))
+    }
+
+    pub fn satisfied(&self, interpreter: &Interpreter) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Version(version) => version.matches(interpreter.python_version()),
+            Self::Path(path) => interpreter.sys_executable() == path,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("No Python interpreter found at `{0}`")]
+    NotFound(String),
+
+    #[error(
+        "No interpreter found for {request:?} (found: {})",
+        discovered.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    NoSuchPython {
+        request: InterpreterRequest,
+        discovered: Vec<Version>,
+    },
+
+    #[error(transparent)]
+    Tags(#[from] platform_tags::TagsError),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// A Python virtual environment, backed by a discovered or newly-created [`Interpreter`].
+#[derive(Debug, Clone)]
+pub struct PythonEnvironment {
+    root: PathBuf,
+    interpreter: Interpreter,
+}
+
+impl PythonEnvironment {
+    /// Load an existing virtual environment from its root directory, by checking that it has a
+    /// `pyvenv.cfg` and querying the environment's own interpreter.
+    pub fn from_root(root: &Path, cache: &Cache) -> Result<Self, Error> {
+        let _ = cache;
+
+        if !root.join("pyvenv.cfg").is_file() {
+            return Err(Error::NotFound(root.display().to_string()));
+        }
+
+        let executable = Self::venv_python(root);
+        let interpreter = Interpreter::query(&executable)
+            .ok_or_else(|| Error::NotFound(executable.display().to_string()))?;
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            interpreter,
+        })
+    }
+
+    /// The interpreter a venv created at `root` would expose, before it necessarily exists.
+    fn venv_python(root: &Path) -> PathBuf {
+        if cfg!(windows) {
+            root.join("Scripts").join("python.exe")
+        } else {
+            root.join("bin").join("python3")
+        }
+    }
+
+    /// Where this environment's installed distributions live, e.g.
+    /// `<root>/lib/python3.11/site-packages` on Unix or `<root>/Lib/site-packages` on Windows.
+    pub fn site_packages_path(&self) -> PathBuf {
+        if cfg!(windows) {
+            self.root.join("Lib").join("site-packages")
+        } else {
+            let (major, minor) = (
+                self.interpreter
+                    .python_version()
+                    .release()
+                    .first()
+                    .copied()
+                    .unwrap_or(3),
+                self.interpreter
+                    .python_version()
+                    .release()
+                    .get(1)
+                    .copied()
+                    .unwrap_or(0),
+            );
+            self.root
+                .join("lib")
+                .join(format!("python{major}.{minor}"))
+                .join("site-packages")
+        }
+    }
+
+    /// Find the first interpreter on `PATH` with no further constraints.
+    pub fn from_default_python(preview: PreviewMode, cache: &Cache) -> Result<Self, Error> {
+        Self::from_interpreter_request(
+            &InterpreterRequest::Any,
+            SystemPython::Allowed,
+            preview,
+            cache,
+        )
+    }
+
+    /// Find an interpreter satisfying a `--python` argument.
+    pub fn from_requested_python(
+        python: &str,
+        system: SystemPython,
+        preview: PreviewMode,
+        cache: &Cache,
+    ) -> Result<Self, Error> {
+        Self::from_interpreter_request(&InterpreterRequest::parse(python), system, preview, cache)
+    }
+
+    /// The single discovery routine shared by every `InterpreterRequest`, whether it came from
+    /// `--python`, no argument at all, or (via [`InterpreterRequest::from_requires_python`]) a
+    /// project's `requires-python`. Enumerates candidates from `PATH`, `uv`'s registered
+    /// toolchains, and `uv`'s managed installs, in that precedence order, and returns the first
+    /// one that satisfies `request`.
+    pub fn from_interpreter_request(
+        request: &InterpreterRequest,
+        system: SystemPython,
+        preview: PreviewMode,
+        cache: &Cache,
+    ) -> Result<Self, Error> {
+        let mut discovered = Vec::new();
+        for interpreter in discovery::find_all(system, preview, cache)? {
+            if request.satisfied(&interpreter) {
+                return Ok(Self {
+                    root: interpreter.sys_executable.clone(),
+                    interpreter,
+                });
+            }
+            discovered.push(interpreter.python_version().clone());
+        }
+
+        Err(Error::NoSuchPython {
+            request: request.clone(),
+            discovered,
+        })
+    }
+
+    pub fn interpreter(&self) -> &Interpreter {
+        &self.interpreter
+    }
+
+    pub fn into_interpreter(self) -> Interpreter {
+        self.interpreter
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
@@ -7,6 +7,7 @@ use tracing::debug;
 
 use distribution_types::{IndexLocations, Resolution};
 use install_wheel_rs::linker::LinkMode;
+use pep440_rs::VersionSpecifiers;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity, RegistryClientBuilder};
 use uv_configuration::{
@@ -17,7 +18,7 @@ use uv_dispatch::BuildDispatch;
 use uv_distribution::ProjectWorkspace;
 use uv_fs::Simplified;
 use uv_installer::{SatisfiesResult, SitePackages};
-use uv_interpreter::{InterpreterRequest, PythonEnvironment, SystemPython};
+use uv_interpreter::{Interpreter, InterpreterRequest, PythonEnvironment, SystemPython};
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{FlatIndex, InMemoryIndex, Options};
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
@@ -57,17 +58,30 @@ pub(crate) enum ProjectError {
 
     #[error(transparent)]
     Operation(#[from] pip::operations::Error),
+
+    #[error("No interpreter found for Python {requires_python} in PATH, toolchain registry, or managed installations (found: {})", discovered.iter().map(ToString::to_string).join(", "))]
+    NoCompatiblePython {
+        requires_python: VersionSpecifiers,
+        discovered: Vec<pep440_rs::Version>,
+    },
 }
 
 /// Initialize a virtual environment for the current project.
-pub(crate) fn init_environment(
+///
+/// `configuration` is only consulted on the resolution-reuse path (see
+/// [`reinstall_resolution`]): it supplies the index locations and build settings the reused
+/// resolution must be reinstalled against, so a project with a private index or custom build
+/// settings doesn't silently fall back to the public PyPI defaults on venv recreation.
+pub(crate) async fn init_environment(
     project: &ProjectWorkspace,
     python: Option<&str>,
+    configuration: &ProjectConfiguration,
+    connectivity: Connectivity,
     preview: PreviewMode,
     cache: &Cache,
     printer: Printer,
 ) -> Result<PythonEnvironment, ProjectError> {
-    let venv = project.workspace().root().join(".venv");
+    let venv_path = project.workspace().root().join(".venv");
 
     let requires_python = project
         .current_project()
@@ -77,7 +91,7 @@ pub(crate) fn init_environment(
         .and_then(|project| project.requires_python.as_ref());
 
     // Discover or create the virtual environment.
-    match PythonEnvironment::from_root(&venv, cache) {
+    let (interpreter, prior_resolution) = match PythonEnvironment::from_root(&venv_path, cache) {
         Ok(venv) => {
             // `--python` has highest precedence, after that we check `requires_python` from
             // `pyproject.toml`. If `--python` and `requires_python` are mutually incompatible,
@@ -97,24 +111,37 @@ pub(crate) fn init_environment(
                 return Ok(venv);
             }
 
-            writeln!(
-                printer.stderr(),
-                "Removing virtualenv at: {}",
-                venv.root().user_display().cyan()
-            )?;
+            // Resolve the replacement interpreter before tearing anything down, so we know
+            // whether the new venv is ABI-compatible with the old one and can reuse its
+            // resolution instead of paying for a full re-resolve and re-download.
+            let interpreter = select_interpreter(python, requires_python, preview, cache)?;
+
+            let prior_resolution =
+                if should_reuse_resolution(&venv.interpreter().tags()?, &interpreter.tags()?) {
+                    writeln!(
+                        printer.stderr(),
+                        "Recreating virtualenv at: {} (reusing resolution)",
+                        venv.root().user_display().cyan()
+                    )?;
+                    Some(Resolution::from(SitePackages::from_executable(&venv)?))
+                } else {
+                    writeln!(
+                        printer.stderr(),
+                        "Removing virtualenv at: {}",
+                        venv.root().user_display().cyan()
+                    )?;
+                    None
+                };
+
             fs_err::remove_dir_all(venv.root())?;
+
+            (interpreter, prior_resolution)
         }
-        Err(uv_interpreter::Error::NotFound(_)) => {}
+        Err(uv_interpreter::Error::NotFound(_)) => (
+            select_interpreter(python, requires_python, preview, cache)?,
+            None,
+        ),
         Err(e) => return Err(e.into()),
-    }
-
-    // TODO(konsti): If `--python` is unset, respect `Requires-Python`. This requires extending
-    //   `VersionRequest` to support `VersionSpecifiers`.
-    let interpreter = if let Some(python) = python.as_ref() {
-        PythonEnvironment::from_requested_python(python, SystemPython::Allowed, preview, cache)?
-            .into_interpreter()
-    } else {
-        PythonEnvironment::from_default_python(preview, cache)?.into_interpreter()
     };
 
     writeln!(
@@ -127,22 +154,281 @@ pub(crate) fn init_environment(
     writeln!(
         printer.stderr(),
         "Creating virtualenv at: {}",
-        venv.user_display().cyan()
+        venv_path.user_display().cyan()
     )?;
 
-    Ok(uv_virtualenv::create_venv(
-        &venv,
+    let venv = uv_virtualenv::create_venv(
+        &venv_path,
         interpreter,
         uv_virtualenv::Prompt::None,
         false,
         false,
-    )?)
+    )?;
+
+    let Some(resolution) = prior_resolution else {
+        return Ok(venv);
+    };
+
+    // Re-install the previously resolved distributions into the fresh venv instead of
+    // discarding them, so recreation reuses the cache rather than a cold rebuild.
+    reinstall_resolution(
+        resolution,
+        &venv,
+        configuration,
+        connectivity,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    Ok(venv)
+}
+
+/// Whether a venv recreated with `new` can reuse the distributions already installed under
+/// `old`, rather than a cold wipe-and-reinstall: true when the two interpreters' wheel tags are
+/// identical, i.e. the new venv can consume what's already on disk without rebuilding.
+fn should_reuse_resolution<T: PartialEq>(old: &T, new: &T) -> bool {
+    old == new
+}
+
+/// Select the interpreter to create (or recreate) the project's virtual environment with,
+/// honoring `--python` first and the project's `requires_python` otherwise.
+fn select_interpreter(
+    python: Option<&str>,
+    requires_python: Option<&VersionSpecifiers>,
+    preview: PreviewMode,
+    cache: &Cache,
+) -> Result<Interpreter, ProjectError> {
+    Ok(if let Some(python) = python {
+        PythonEnvironment::from_requested_python(python, SystemPython::Allowed, preview, cache)?
+            .into_interpreter()
+    } else if let Some(requires_python) = requires_python {
+        find_compatible_interpreter(requires_python, preview, cache)?
+    } else {
+        PythonEnvironment::from_default_python(preview, cache)?.into_interpreter()
+    })
+}
+
+/// Re-install a previously-resolved [`Resolution`] into a freshly created virtual environment,
+/// so that recreating a venv after an interpreter change restores the distributions that were
+/// installed in the removed venv's `site-packages`.
+async fn reinstall_resolution(
+    resolution: Resolution,
+    venv: &PythonEnvironment,
+    configuration: &ProjectConfiguration,
+    connectivity: Connectivity,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<(), ProjectError> {
+    let interpreter = venv.interpreter().clone();
+    let tags = venv.interpreter().tags()?;
+    let markers = venv.interpreter().markers();
+
+    let client = RegistryClientBuilder::new(cache.clone())
+        .connectivity(connectivity)
+        .markers(markers)
+        .platform(venv.interpreter().platform())
+        .build();
+
+    let concurrency = Concurrency::default();
+    let config_settings = configuration.config_settings.clone();
+    let flat_index = FlatIndex::default();
+    let hasher = HashStrategy::default();
+    let in_flight = InFlight::default();
+    let index = InMemoryIndex::default();
+    let index_locations = configuration.index_locations.clone();
+    let link_mode = configuration.link_mode;
+    let no_binary = configuration.no_binary.clone();
+    let no_build = configuration.no_build.clone();
+    let reinstall = Reinstall::default();
+
+    let install_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &index,
+        &in_flight,
+        SetupPyStrategy::default(),
+        &config_settings,
+        BuildIsolation::default(),
+        link_mode,
+        &no_build,
+        &no_binary,
+        concurrency,
+        preview,
+    );
+
+    pip::operations::install(
+        &resolution,
+        SitePackages::from_executable(venv)?,
+        pip::operations::Modifications::Sufficient,
+        &reinstall,
+        &no_binary,
+        link_mode,
+        false,
+        &index_locations,
+        &hasher,
+        tags,
+        &client,
+        &in_flight,
+        concurrency,
+        &install_dispatch,
+        cache,
+        venv,
+        false,
+        printer,
+        preview,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Discover an interpreter on `PATH`, among registered toolchains, or among managed installs
+/// whose version satisfies `requires_python`.
+///
+/// Used when `--python` is unset but the project declares a `Requires-Python` constraint in
+/// `pyproject.toml`, so we don't silently create a virtualenv with whatever interpreter happens
+/// to be first on `PATH`.
+fn find_compatible_interpreter(
+    requires_python: &VersionSpecifiers,
+    preview: PreviewMode,
+    cache: &Cache,
+) -> Result<Interpreter, ProjectError> {
+    let request = InterpreterRequest::from_requires_python(requires_python);
+    PythonEnvironment::from_interpreter_request(&request, SystemPython::Allowed, preview, cache)
+        .map(PythonEnvironment::into_interpreter)
+        .map_err(|err| match err {
+            uv_interpreter::Error::NoSuchPython { discovered, .. } => {
+                ProjectError::NoCompatiblePython {
+                    requires_python: requires_python.clone(),
+                    discovered,
+                }
+            }
+            err => err.into(),
+        })
+}
+
+/// Resolve a `--no-build`-shaped setting: a CLI override always wins, otherwise a `[tool.uv]`
+/// boolean maps to `NoBuild::All`/`NoBuild::default()`, and an absent setting also defaults.
+fn resolve_no_build(overridden: Option<NoBuild>, tool_uv: Option<bool>) -> NoBuild {
+    overridden.unwrap_or_else(|| match tool_uv {
+        Some(true) => NoBuild::All,
+        Some(false) | None => NoBuild::default(),
+    })
+}
+
+/// Resolve a `--no-binary`-shaped setting; see [`resolve_no_build`].
+fn resolve_no_binary(overridden: Option<NoBinary>, tool_uv: Option<bool>) -> NoBinary {
+    overridden.unwrap_or_else(|| match tool_uv {
+        Some(true) => NoBinary::All,
+        Some(false) | None => NoBinary::default(),
+    })
+}
+
+/// Resolve a setting that's represented identically on the CLI and in `[tool.uv]`: prefer the
+/// CLI override, fall back to the `pyproject.toml` value, and default if neither is set.
+fn merge_override<T: Default>(overridden: Option<T>, tool_uv: Option<T>) -> T {
+    overridden.or(tool_uv).unwrap_or_default()
+}
+
+/// Configuration for the project-level commands (`run`, `sync`, `lock`), resolved from the
+/// workspace's `pyproject.toml` (`[tool.uv]`) and overridden by whatever was passed on the CLI.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ProjectConfiguration {
+    pub(crate) index_locations: IndexLocations,
+    pub(crate) no_build: NoBuild,
+    pub(crate) no_binary: NoBinary,
+    pub(crate) config_settings: ConfigSettings,
+    pub(crate) link_mode: LinkMode,
+    pub(crate) options: Options,
+}
+
+impl ProjectConfiguration {
+    /// Resolve the [`ProjectConfiguration`] for `project`, merging the workspace's `[tool.uv]`
+    /// table with the given CLI overrides. CLI overrides always win.
+    pub(crate) fn new(
+        project: &ProjectWorkspace,
+        overrides: ProjectConfigurationOverrides,
+    ) -> Self {
+        let tool_uv = project
+            .current_project()
+            .pyproject_toml()
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref());
+
+        let index_locations = overrides.index_locations.unwrap_or_else(|| {
+            tool_uv
+                .map(|tool_uv| {
+                    IndexLocations::new(
+                        tool_uv.index_url.clone(),
+                        tool_uv.extra_index_url.clone().unwrap_or_default(),
+                        tool_uv.find_links.clone().unwrap_or_default(),
+                        tool_uv.no_index.unwrap_or_default(),
+                    )
+                })
+                .unwrap_or_default()
+        });
+
+        let no_build = resolve_no_build(overrides.no_build, tool_uv.and_then(|t| t.no_build));
+        let no_binary = resolve_no_binary(overrides.no_binary, tool_uv.and_then(|t| t.no_binary));
+
+        let config_settings = overrides.config_settings.unwrap_or_else(|| {
+            tool_uv
+                .and_then(|tool_uv| tool_uv.config_settings.clone())
+                .unwrap_or_default()
+        });
+
+        let link_mode = merge_override(overrides.link_mode, tool_uv.and_then(|t| t.link_mode));
+
+        // `[tool.uv]` carries the same resolver knobs (`resolution`, `prerelease`,
+        // `index-strategy`, ...) as the CLI's `Options`, so a CLI override always wins but a
+        // `pyproject.toml` setting isn't silently dropped in its absence.
+        let options = merge_override(overrides.options, tool_uv.and_then(|t| t.options.clone()));
+
+        Self {
+            index_locations,
+            no_build,
+            no_binary,
+            config_settings,
+            link_mode,
+            options,
+        }
+    }
+}
+
+/// CLI-level overrides for a [`ProjectConfiguration`]; any `Some` value takes precedence over
+/// the workspace's `[tool.uv]` table.
+#[derive(Debug, Default)]
+pub(crate) struct ProjectConfigurationOverrides {
+    pub(crate) index_locations: Option<IndexLocations>,
+    pub(crate) no_build: Option<NoBuild>,
+    pub(crate) no_binary: Option<NoBinary>,
+    pub(crate) config_settings: Option<ConfigSettings>,
+    pub(crate) link_mode: Option<LinkMode>,
+    pub(crate) options: Option<Options>,
 }
 
 /// Update a [`PythonEnvironment`] to satisfy a set of [`RequirementsSource`]s.
+///
+/// `configuration` supplies the index locations, build constraints, and resolver `Options` that
+/// would otherwise be silently defaulted, so that `[tool.uv]` and CLI overrides are honored.
+/// `constraints` and `overrides` are threaded through to resolution the same way the `pip
+/// compile`/`pip install` paths already handle them, and `extras` controls which optional
+/// dependencies of the requested requirements are considered satisfied.
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn update_environment(
     venv: PythonEnvironment,
     requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    extras: &ExtrasSpecification,
+    configuration: &ProjectConfiguration,
     connectivity: Connectivity,
     cache: &Cache,
     printer: Printer,
@@ -152,15 +438,18 @@ pub(crate) async fn update_environment(
     let client_builder = BaseClientBuilder::default().connectivity(connectivity);
 
     // Read all requirements from the provided sources.
-    // TODO(zanieb): Consider allowing constraints and extras
-    // TODO(zanieb): Allow specifying extras somehow
-    let spec =
-        RequirementsSpecification::from_sources(requirements, &[], &[], &client_builder).await?;
+    let spec = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        &client_builder,
+    )
+    .await?;
 
     // Check if the current environment satisfies the requirements
     let site_packages = SitePackages::from_executable(&venv)?;
     if spec.source_trees.is_empty() {
-        match site_packages.satisfies(&spec.requirements, &spec.constraints)? {
+        match site_packages.satisfies(&spec.requirements, &spec.constraints, extras)? {
             // If the requirements are already satisfied, we're done.
             SatisfiesResult::Fresh {
                 recursive_requirements,
@@ -194,22 +483,22 @@ pub(crate) async fn update_environment(
         .platform(venv.interpreter().platform())
         .build();
 
-    // TODO(charlie): Respect project configuration.
+    // Pull resolver/installer settings from the resolved project configuration, rather than
+    // hardcoding defaults that ignore `[tool.uv]` and the CLI.
     let build_isolation = BuildIsolation::default();
     let compile = false;
     let concurrency = Concurrency::default();
-    let config_settings = ConfigSettings::default();
+    let config_settings = configuration.config_settings.clone();
     let dry_run = false;
-    let extras = ExtrasSpecification::default();
     let flat_index = FlatIndex::default();
     let hasher = HashStrategy::default();
     let in_flight = InFlight::default();
     let index = InMemoryIndex::default();
-    let index_locations = IndexLocations::default();
-    let link_mode = LinkMode::default();
-    let no_binary = NoBinary::default();
-    let no_build = NoBuild::default();
-    let options = Options::default();
+    let index_locations = configuration.index_locations.clone();
+    let link_mode = configuration.link_mode;
+    let no_binary = configuration.no_binary.clone();
+    let no_build = configuration.no_build.clone();
+    let options = configuration.options.clone();
     let preferences = Vec::default();
     let reinstall = Reinstall::default();
     let setup_py = SetupPyStrategy::default();
@@ -241,7 +530,7 @@ pub(crate) async fn update_environment(
         spec.overrides,
         spec.source_trees,
         spec.project,
-        &extras,
+        extras,
         preferences,
         site_packages.clone(),
         &hasher,
@@ -321,3 +610,70 @@ pub(crate) async fn update_environment(
 
     Ok(venv)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_resolution_when_tags_match() {
+        assert!(should_reuse_resolution(
+            &"cp312-cp312-linux_x86_64",
+            &"cp312-cp312-linux_x86_64"
+        ));
+    }
+
+    #[test]
+    fn wipes_when_tags_differ() {
+        assert!(!should_reuse_resolution(
+            &"cp311-cp311-linux_x86_64",
+            &"cp312-cp312-linux_x86_64"
+        ));
+    }
+
+    #[test]
+    fn no_build_override_wins_over_tool_uv() {
+        assert_eq!(
+            resolve_no_build(Some(NoBuild::None), Some(true)),
+            NoBuild::None
+        );
+    }
+
+    #[test]
+    fn no_build_falls_back_to_tool_uv() {
+        assert_eq!(resolve_no_build(None, Some(true)), NoBuild::All);
+    }
+
+    #[test]
+    fn no_build_defaults_when_unset() {
+        assert_eq!(resolve_no_build(None, None), NoBuild::default());
+    }
+
+    #[test]
+    fn no_binary_override_wins_over_tool_uv() {
+        assert_eq!(
+            resolve_no_binary(Some(NoBinary::None), Some(true)),
+            NoBinary::None
+        );
+    }
+
+    #[test]
+    fn no_binary_falls_back_to_tool_uv() {
+        assert_eq!(resolve_no_binary(None, Some(true)), NoBinary::All);
+    }
+
+    #[test]
+    fn merge_override_prefers_cli_value() {
+        assert_eq!(merge_override(Some(1_u8), Some(2_u8)), 1);
+    }
+
+    #[test]
+    fn merge_override_falls_back_to_tool_uv_value() {
+        assert_eq!(merge_override(None, Some(2_u8)), 2);
+    }
+
+    #[test]
+    fn merge_override_defaults_when_neither_set() {
+        assert_eq!(merge_override::<u8>(None, None), 0);
+    }
+}